@@ -0,0 +1,284 @@
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{read, Event},
+    execute, queue,
+    style::{Print, StyledContent},
+    terminal::{
+        self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
+};
+use std::io::{self, Write};
+
+/// Abstraction over the terminal so the UI isn't hard-wired to crossterm stdout.
+///
+/// The render and input routines are generic over this trait, which lets the real
+/// [`CrosstermBackend`] be swapped for an alternate implementation (a headless
+/// recording backend for tests, or a termion/curses one) without touching any of
+/// the game logic.
+pub trait Backend {
+    /// Current `(width, height)` of the drawing surface in cells.
+    fn size(&self) -> (u16, u16);
+    /// Switch the surface into raw / full-screen mode.
+    fn enter_raw(&mut self) -> io::Result<()>;
+    /// Restore the surface to its original cooked state.
+    fn leave_raw(&mut self) -> io::Result<()>;
+    /// Clear everything and park the cursor at the origin.
+    fn clear(&mut self) -> io::Result<()>;
+    /// Move the cursor to `(x, y)`.
+    fn move_to(&mut self, x: u16, y: u16) -> io::Result<()>;
+    /// Print a styled run of text at the cursor.
+    fn print_styled(&mut self, content: StyledContent<String>) -> io::Result<()>;
+    /// Flush any buffered output to the surface.
+    fn flush(&mut self) -> io::Result<()>;
+    /// Block until the next input event arrives.
+    fn read_event(&mut self) -> io::Result<Event>;
+}
+
+/// [`Backend`] backed by crossterm writing to real stdout.
+pub struct CrosstermBackend {
+    stdout: io::Stdout,
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        Self {
+            stdout: io::stdout(),
+        }
+    }
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backend for CrosstermBackend {
+    fn size(&self) -> (u16, u16) {
+        terminal::size().unwrap_or((80, 24))
+    }
+
+    fn enter_raw(&mut self) -> io::Result<()> {
+        #[cfg(windows)]
+        {
+            let _ = terminal::enable_ansi_support();
+            maximize_console_window();
+        }
+        execute!(self.stdout, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
+        enable_raw_mode()
+    }
+
+    fn leave_raw(&mut self) -> io::Result<()> {
+        let _ = disable_raw_mode();
+        execute!(self.stdout, Show, LeaveAlternateScreen)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        queue!(self.stdout, Clear(ClearType::All), MoveTo(0, 0))
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        queue!(self.stdout, MoveTo(x, y))
+    }
+
+    fn print_styled(&mut self, content: StyledContent<String>) -> io::Result<()> {
+        queue!(self.stdout, Print(content))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stdout.flush()
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        read()
+    }
+}
+
+/// Restore the real terminal to its cooked state: leave raw mode, show the
+/// cursor and drop back out of the alternate screen.
+///
+/// Safe to call more than once, so the signal handler and the panic hook can
+/// both use it, even after [`CrosstermBackend::leave_raw`] has already run on
+/// the `TermGuard` drop path.
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+}
+
+/// Install a SIGINT/SIGTERM handler that restores the terminal before exiting.
+///
+/// In raw mode Ctrl-C is delivered as a key event rather than a normal signal,
+/// but a `kill`/`SIGTERM` (or a Ctrl-C seen before raw mode is armed) would
+/// otherwise leave the alternate screen wrecked.
+pub fn install_signal_handler() {
+    let _ = ctrlc::set_handler(|| {
+        restore_terminal();
+        std::process::exit(130);
+    });
+}
+
+/// Install a panic hook that restores the terminal before the default hook runs,
+/// so a panic inside a draw call can't leave the screen in raw/alternate mode.
+pub fn install_panic_hook() {
+    let default = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        default(info);
+    }));
+}
+
+/// Headless [`Backend`] that renders into an in-memory character grid and is
+/// driven by a scripted queue of [`Event`]s instead of a real TTY.
+///
+/// This makes the whole quiz flow deterministically testable in CI: tests push
+/// key presses with [`TestBackend::script_key`], run `ask_mc` / `final_lock`
+/// against the backend, then assert on the rendered grid via
+/// [`TestBackend::buffer_to_string`] / [`TestBackend::assert_contains`].
+#[cfg(test)]
+pub struct TestBackend {
+    width: u16,
+    height: u16,
+    cursor: (u16, u16),
+    cells: Vec<char>,
+    styles: Vec<crossterm::style::ContentStyle>,
+    events: std::collections::VecDeque<Event>,
+}
+
+#[cfg(test)]
+impl TestBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        let len = width as usize * height as usize;
+        Self {
+            width,
+            height,
+            cursor: (0, 0),
+            cells: vec![' '; len],
+            styles: vec![crossterm::style::ContentStyle::default(); len],
+            events: std::collections::VecDeque::new(),
+        }
+    }
+
+    /// Enqueue a single `KeyCode` as the next scripted input event.
+    pub fn script_key(&mut self, code: crossterm::event::KeyCode) {
+        self.events.push_back(Event::Key(crossterm::event::KeyEvent::new(
+            code,
+            crossterm::event::KeyModifiers::NONE,
+        )));
+    }
+
+    /// Enqueue a fully-specified key event (with modifiers) as the next input.
+    pub fn script_key_event(&mut self, event: crossterm::event::KeyEvent) {
+        self.events.push_back(Event::Key(event));
+    }
+
+    /// Enqueue each character in `keys` as an individual key press.
+    pub fn script_chars(&mut self, keys: &str) {
+        for ch in keys.chars() {
+            self.script_key(crossterm::event::KeyCode::Char(ch));
+        }
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y as usize * self.width as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The style recorded at `(x, y)`, or the default style if out of bounds.
+    pub fn style_at(&self, x: u16, y: u16) -> crossterm::style::ContentStyle {
+        self.index(x, y)
+            .map(|i| self.styles[i])
+            .unwrap_or_default()
+    }
+
+    /// Render the grid as newline-separated rows with trailing spaces trimmed.
+    pub fn buffer_to_string(&self) -> String {
+        (0..self.height)
+            .map(|y| {
+                let start = y as usize * self.width as usize;
+                let end = start + self.width as usize;
+                let row: String = self.cells[start..end].iter().collect();
+                row.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Panic unless `text` appears somewhere in the rendered grid.
+    pub fn assert_contains(&self, text: &str) {
+        let buffer = self.buffer_to_string();
+        assert!(
+            buffer.contains(text),
+            "expected buffer to contain {text:?}, got:\n{buffer}"
+        );
+    }
+}
+
+#[cfg(test)]
+impl Backend for TestBackend {
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    fn enter_raw(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn leave_raw(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.cells.iter_mut().for_each(|c| *c = ' ');
+        self.styles
+            .iter_mut()
+            .for_each(|s| *s = crossterm::style::ContentStyle::default());
+        self.cursor = (0, 0);
+        Ok(())
+    }
+
+    fn move_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.cursor = (x, y);
+        Ok(())
+    }
+
+    fn print_styled(&mut self, content: StyledContent<String>) -> io::Result<()> {
+        let style = *content.style();
+        let (mut x, y) = self.cursor;
+        for ch in content.content().chars() {
+            if let Some(i) = self.index(x, y) {
+                self.cells[i] = ch;
+                self.styles[i] = style;
+            }
+            x = x.saturating_add(1);
+        }
+        self.cursor = (x, y);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        self.events.pop_front().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "scripted event queue exhausted")
+        })
+    }
+}
+
+#[cfg(windows)]
+fn maximize_console_window() {
+    use windows_sys::Win32::System::Console::GetConsoleWindow;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{ShowWindow, SW_MAXIMIZE};
+
+    unsafe {
+        let hwnd = GetConsoleWindow();
+        if hwnd != 0 {
+            let _ = ShowWindow(hwnd, SW_MAXIMIZE);
+        }
+    }
+}
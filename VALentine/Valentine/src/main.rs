@@ -1,79 +1,107 @@
-
-
+mod backend;
+mod questions;
 
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
-    event::{read, Event, KeyCode},
-    execute, queue,
-    style::{Print, Stylize},
-    terminal::{
-        self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
-        LeaveAlternateScreen,
-    },
+    event::{Event, KeyCode, KeyEvent, KeyModifiers},
+    style::{StyledContent, Stylize},
 };
-use std::io::{self, Write};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use backend::{Backend, CrosstermBackend};
+use questions::{load_questions, OwnedQuestion};
+
 type CtResult<T> = std::io::Result<T>;
 
+/// Ensures the terminal always resets correctly by owning the [`Backend`] and
+/// restoring it on `Drop`.
+struct TermGuard<B: Backend> {
+    backend: B,
+}
 
-/// Ensures terminal always resets correctly
-struct TermGuard;
+impl<B: Backend> TermGuard<B> {
+    fn init(mut backend: B) -> CtResult<Self> {
+        backend.enter_raw()?;
+        Ok(Self { backend })
+    }
 
-impl TermGuard {
-    fn init() -> CtResult<Self> {
-        let mut stdout = io::stdout();
-        #[cfg(windows)]
-        {
-            let _ = terminal::enable_ansi_support();
-            maximize_console_window();
-        }
-        execute!(stdout, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
-        enable_raw_mode()?;
-        Ok(Self)
+    fn backend(&mut self) -> &mut B {
+        &mut self.backend
     }
 }
 
-impl Drop for TermGuard {
+impl<B: Backend> Drop for TermGuard<B> {
     fn drop(&mut self) {
-        let mut stdout = io::stdout();
-        let _ = disable_raw_mode();
-        let _ = execute!(stdout, Show, LeaveAlternateScreen);
+        let _ = self.backend.leave_raw();
     }
 }
 
-#[cfg(windows)]
-fn maximize_console_window() {
-    use windows_sys::Win32::System::Console::GetConsoleWindow;
-    use windows_sys::Win32::UI::WindowsAndMessaging::{ShowWindow, SW_MAXIMIZE};
+/// The error raised when the player aborts the quiz mid-run. It travels up
+/// through the `?` chain so `TermGuard` can restore the terminal on the way out.
+fn aborted() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Interrupted, "aborted by user")
+}
 
-    unsafe {
-        let hwnd = GetConsoleWindow();
-        if hwnd != 0 {
-            let _ = ShowWindow(hwnd, SW_MAXIMIZE);
+/// Esc or Ctrl-Q is the universal "get me out of here" gesture.
+fn is_abort_key(k: &KeyEvent) -> bool {
+    matches!(k.code, KeyCode::Esc)
+        || (k.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(k.code, KeyCode::Char('q') | KeyCode::Char('Q')))
+}
+
+/// Per-lock outcome shown on the review screen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum LockState {
+    /// Not attempted yet.
+    Pending,
+    /// Answered correctly.
+    Passed,
+    /// Answered wrongly `n` times and not yet passed.
+    Retried(usize),
+}
+
+impl LockState {
+    /// Single-character State-column glyph.
+    fn glyph(self) -> &'static str {
+        match self {
+            LockState::Pending => "·",
+            LockState::Passed => "✔",
+            LockState::Retried(_) => "…",
         }
     }
-}
 
-struct McQuestion {
-    title: &'static str,
-    prompt: &'static str,
-    a: &'static str,
-    b: &'static str,
-    c: &'static str,
-    d: &'static str,
-    correct: char,
-    wrong_msg: &'static str,
+    /// Result-column label.
+    fn result(self) -> String {
+        match self {
+            LockState::Pending => "pending".to_string(),
+            LockState::Passed => "passed".to_string(),
+            LockState::Retried(n) => format!("retried {n}x"),
+        }
+    }
 }
 
-fn term_size() -> (u16, u16) {
-    terminal::size().unwrap_or((80, 24))
+/// Running outcome for every lock, used to render and revisit progress.
+struct Progress {
+    states: Vec<LockState>,
 }
 
-fn clear(stdout: &mut impl Write) -> CtResult<()> {
-    queue!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
-    Ok(())
+impl Progress {
+    fn new(count: usize) -> Self {
+        Self {
+            states: vec![LockState::Pending; count],
+        }
+    }
+
+    fn record_wrong(&mut self, idx: usize) {
+        self.states[idx] = match self.states[idx] {
+            LockState::Retried(n) => LockState::Retried(n + 1),
+            _ => LockState::Retried(1),
+        };
+    }
+
+    fn record_pass(&mut self, idx: usize) {
+        self.states[idx] = LockState::Passed;
+    }
 }
 
 fn center_x(width: u16, text: &str) -> u16 {
@@ -81,8 +109,86 @@ fn center_x(width: u16, text: &str) -> u16 {
     width.saturating_sub(len).saturating_div(2)
 }
 
+/// Wrap a single logical line to `width`, breaking on word boundaries and
+/// hard-breaking any token that is itself longer than the line. An empty line
+/// stays a single empty row so blank separators survive.
+fn wrap_line(line: &str, width: u16) -> Vec<String> {
+    let width = width.max(1) as usize;
+    if line.is_empty() {
+        return vec![String::new()];
+    }
+
+    let mut out = Vec::new();
+    let mut current = String::new();
+    for word in line.split(' ') {
+        if word.chars().count() > width {
+            // Token longer than the line: flush what we have, then hard-break it.
+            if !current.is_empty() {
+                out.push(std::mem::take(&mut current));
+            }
+            for ch in word.chars() {
+                if current.chars().count() == width {
+                    out.push(std::mem::take(&mut current));
+                }
+                current.push(ch);
+            }
+            continue;
+        }
+
+        let gap = usize::from(!current.is_empty());
+        if current.chars().count() + gap + word.chars().count() > width {
+            out.push(std::mem::take(&mut current));
+            current.push_str(word);
+        } else {
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+    }
+    if !current.is_empty() || out.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+/// A styled, unstyled-text logical line carried through the layout pass.
+///
+/// The wrapping and centring work on the *visible* text (`content`); the style
+/// is (re)applied only once each row is emitted, so it can never be measured or
+/// hard-broken mid escape-sequence.
+type Line = StyledContent<String>;
+
+/// Convenience for an unstyled line of visible text.
+fn plain(text: impl Into<String>) -> Line {
+    text.into().stylize()
+}
+
+/// Flatten styled logical lines into wrapped rows, wrapping on the visible text
+/// and re-applying each line's style to every row it produces.
+fn wrap_lines(lines: &[Line], width: u16) -> Vec<Line> {
+    lines
+        .iter()
+        .flat_map(|line| {
+            let style = *line.style();
+            wrap_line(line.content(), width)
+                .into_iter()
+                .map(move |row| StyledContent::new(style, row))
+        })
+        .collect()
+}
+
+/// Vertical row at which a block of `line_count` rows starts so it stays
+/// centered between the title and the footer/status bar.
+fn vertical_start(height: u16, line_count: u16) -> u16 {
+    let top = 4u16; // first row below the title
+    let bottom = height.saturating_sub(3); // first row occupied by the footer
+    let avail = bottom.saturating_sub(top);
+    top + avail.saturating_sub(line_count) / 2
+}
+
 fn draw_status_bar(
-    stdout: &mut impl Write,
+    backend: &mut impl Backend,
     width: u16,
     height: u16,
     lock_idx: usize,
@@ -97,71 +203,86 @@ fn draw_status_bar(
     let bar_y = height.saturating_sub(1);
     let fill = " ".repeat(width as usize);
 
-    queue!(
-        stdout,
-        MoveTo(0, bar_y),
-        Print(fill.black().on_dark_grey()),
-        MoveTo(1, bar_y),
-        Print(format!("LOCK {}/{}", lock_idx, lock_total).black().on_dark_grey().bold()),
-        MoveTo(center_x(width, &time), bar_y),
-        Print(time.black().on_dark_grey().bold()),
-        MoveTo(width.saturating_sub(status.len() as u16 + 9), bar_y),
-        Print(format!("STATUS: {}", status).black().on_dark_grey().bold()),
-    )?;
+    let lock_text = format!("LOCK {}/{}", lock_idx, lock_total);
+    let status_text = format!("STATUS: {}", status);
+    let lock_end = 1 + lock_text.chars().count() as u16;
+    let time_len = time.chars().count() as u16;
+
+    // Right-align STATUS, but only once it clears the LOCK segment. On a tiny
+    // width STATUS is dropped rather than drawn over the LOCK counter.
+    let status_x = width.saturating_sub(status_text.chars().count() as u16 + 1);
+    let show_status = status_x > lock_end;
+    // Centre the clock between the two, never letting it collide with either.
+    let right_limit = if show_status { status_x } else { width };
+    let time_x = center_x(width, &time).max(lock_end + 1);
+    let show_time = time_x + time_len <= right_limit;
+
+    backend.move_to(0, bar_y)?;
+    backend.print_styled(fill.black().on_dark_grey())?;
+    backend.move_to(1, bar_y)?;
+    backend.print_styled(lock_text.black().on_dark_grey().bold())?;
+    if show_time {
+        backend.move_to(time_x, bar_y)?;
+        backend.print_styled(time.black().on_dark_grey().bold())?;
+    }
+    if show_status {
+        backend.move_to(status_x, bar_y)?;
+        backend.print_styled(status_text.black().on_dark_grey().bold())?;
+    }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn draw_frame(
-    stdout: &mut impl Write,
+    backend: &mut impl Backend,
     title: &str,
-    lines: &[String],
+    lines: &[Line],
     footer: &str,
     lock_idx: usize,
     lock_total: usize,
     status: &str,
     started: Instant,
 ) -> CtResult<()> {
-    let (w, h) = term_size();
-    clear(stdout)?;
-
-    queue!(
-        stdout,
-        MoveTo(center_x(w, title), 1),
-        Print(title.bold())
-    )?;
-
-    for (i, line) in lines.iter().enumerate() {
-        let y = 4 + i as u16;
-        queue!(
-            stdout,
-            MoveTo(center_x(w, line), y),
-            Print(line.clone())
-        )?;
+    let (w, h) = backend.size();
+    backend.clear()?;
+
+    backend.move_to(center_x(w, title), 1)?;
+    backend.print_styled(title.to_string().bold())?;
+
+    let wrapped = wrap_lines(lines, w);
+    let start_y = vertical_start(h, wrapped.len() as u16);
+    for (i, line) in wrapped.iter().enumerate() {
+        // Centre on the visible text, then emit the row with its own style.
+        backend.move_to(center_x(w, line.content()), start_y + i as u16)?;
+        backend.print_styled(line.clone())?;
     }
 
-    queue!(
-        stdout,
-        MoveTo(1, h.saturating_sub(3)),
-        Print(footer.dim())
-    )?;
+    backend.move_to(1, h.saturating_sub(3))?;
+    backend.print_styled(footer.to_string().dim())?;
 
-    draw_status_bar(stdout, w, h, lock_idx, lock_total, status, started)?;
-    stdout.flush()?;
+    draw_status_bar(backend, w, h, lock_idx, lock_total, status, started)?;
+    backend.flush()?;
     Ok(())
 }
 
-fn wait_any_key() -> CtResult<()> {
+fn wait_any_key(backend: &mut impl Backend) -> CtResult<()> {
     loop {
-        if let Event::Key(_) = read()? {
+        if let Event::Key(k) = backend.read_event()? {
+            if is_abort_key(&k) {
+                return Err(aborted());
+            }
             break;
         }
     }
     Ok(())
 }
 
-fn read_abcd() -> CtResult<char> {
+fn read_abcd(backend: &mut impl Backend) -> CtResult<char> {
     loop {
-        if let Event::Key(k) = read()? {
+        if let Event::Key(k) = backend.read_event()? {
+            if is_abort_key(&k) {
+                return Err(aborted());
+            }
             if let KeyCode::Char(c) = k.code {
                 let c = c.to_ascii_uppercase();
                 if matches!(c, 'A' | 'B' | 'C' | 'D') {
@@ -172,9 +293,12 @@ fn read_abcd() -> CtResult<char> {
     }
 }
 
-fn read_yn() -> CtResult<char> {
+fn read_yn(backend: &mut impl Backend) -> CtResult<char> {
     loop {
-        if let Event::Key(k) = read()? {
+        if let Event::Key(k) = backend.read_event()? {
+            if is_abort_key(&k) {
+                return Err(aborted());
+            }
             if let KeyCode::Char(c) = k.code {
                 let c = c.to_ascii_uppercase();
                 if matches!(c, 'Y' | 'N') {
@@ -185,8 +309,8 @@ fn read_yn() -> CtResult<char> {
     }
 }
 
-fn jet_cutscene(stdout: &mut impl Write, started: Instant) -> CtResult<()> {
-    let (w, h) = term_size();
+fn jet_cutscene(backend: &mut impl Backend, lock_total: usize, started: Instant) -> CtResult<()> {
+    let (w, h) = backend.size();
     let y = h / 2;
 
     let jet1 = "    __|__";
@@ -194,50 +318,48 @@ fn jet_cutscene(stdout: &mut impl Write, started: Instant) -> CtResult<()> {
 
     for x in 0..(w.saturating_sub(jet2.len() as u16)) {
         draw_frame(
-            stdout,
+            backend,
             "✈ OPERATION: VALENTINE SORTIE ✈",
-            &vec!["Cleared for takeoff…".green().to_string()],
+            &["Cleared for takeoff…".to_string().green()],
             "Enjoy the flyby 😄",
             0,
-            4,
+            lock_total,
             "RUNNING",
             started,
         )?;
-        queue!(
-            stdout,
-            MoveTo(x, y),
-            Print(jet1),
-            MoveTo(x, y + 1),
-            Print(jet2)
-        )?;
-        stdout.flush()?;
+        backend.move_to(x, y)?;
+        backend.print_styled(jet1.to_string().stylize())?;
+        backend.move_to(x, y + 1)?;
+        backend.print_styled(jet2.to_string().stylize())?;
+        backend.flush()?;
         thread::sleep(Duration::from_millis(18));
     }
     Ok(())
 }
 
 fn ask_mc(
-    stdout: &mut impl Write,
-    q: &McQuestion,
+    backend: &mut impl Backend,
+    q: &OwnedQuestion,
     idx: usize,
     total: usize,
     started: Instant,
+    progress: &mut Progress,
 ) -> CtResult<()> {
     loop {
         let lines = vec![
-            q.prompt.to_string(),
-            "".into(),
-            format!("A) {}", q.a),
-            format!("B) {}", q.b),
-            format!("C) {}", q.c),
-            format!("D) {}", q.d),
-            "".into(),
-            "Press A / B / C / D".into(),
+            plain(q.prompt.clone()),
+            plain(""),
+            plain(format!("A) {}", q.a)),
+            plain(format!("B) {}", q.b)),
+            plain(format!("C) {}", q.c)),
+            plain(format!("D) {}", q.d)),
+            plain(""),
+            plain("Press A / B / C / D"),
         ];
 
         draw_frame(
-            stdout,
-            q.title,
+            backend,
+            &q.title,
             &lines,
             "Choose wisely 🙂",
             idx,
@@ -246,74 +368,73 @@ fn ask_mc(
             started,
         )?;
 
-        let c = read_abcd()?;
+        let c = read_abcd(backend)?;
         if c == q.correct {
+            progress.record_pass(idx - 1);
             draw_frame(
-                stdout,
-                q.title,
-                &vec!["✅ Correct!".green().to_string()],
+                backend,
+                &q.title,
+                &["✅ Correct!".to_string().green()],
                 "Press any key to continue",
                 idx,
                 total,
                 "PASS",
                 started,
             )?;
-            wait_any_key()?;
+            wait_any_key(backend)?;
             break;
         } else {
+            progress.record_wrong(idx - 1);
             draw_frame(
-                stdout,
-                q.title,
-                &vec![
-                    "❌ Incorrect.".red().to_string(),
-                    q.wrong_msg.into(),
-                ],
+                backend,
+                &q.title,
+                &["❌ Incorrect.".to_string().red(), plain(q.wrong_msg.clone())],
                 "Press any key to retry",
                 idx,
                 total,
                 "RETRY",
                 started,
             )?;
-            wait_any_key()?;
+            wait_any_key(backend)?;
         }
     }
     Ok(())
 }
 
-fn final_lock(stdout: &mut impl Write, started: Instant) -> CtResult<()> {
+fn final_lock(backend: &mut impl Backend, lock_total: usize, started: Instant) -> CtResult<()> {
     let mut no_count = 0;
 
     loop {
         draw_frame(
-            stdout,
+            backend,
             "FINAL LOCK",
-            &vec![
-                "Will you be my Valentine? (Y / N)".into(),
-                "(This is easy right? right? 😅)".dim().to_string(),
+            &[
+                plain("Will you be my Valentine? (Y / N)"),
+                "(This is easy right? right? 😅)".to_string().dim(),
             ],
             "Press Y or N",
-            4,
-            4,
+            lock_total,
+            lock_total,
             "AWAITING INPUT",
             started,
         )?;
 
-        match read_yn()? {
+        match read_yn(backend)? {
             'Y' => {
                 draw_frame(
-                    stdout,
+                    backend,
                     "MISSION SUCCESS",
-                    &vec![
-                        "✈ TAKEOFF CLEARED ✈".green().to_string(),
-                        "VALENTINE AUTHORIZED ❤️".into(),
+                    &[
+                        "✈ TAKEOFF CLEARED ✈".to_string().green(),
+                        plain("VALENTINE AUTHORIZED ❤️"),
                     ],
                     "Press any key to exit",
-                    4,
-                    4,
+                    lock_total,
+                    lock_total,
                     "SUCCESS",
                     started,
                 )?;
-                wait_any_key()?;
+                wait_any_key(backend)?;
                 break;
             }
             'N' => {
@@ -324,16 +445,16 @@ fn final_lock(stdout: &mut impl Write, started: Instant) -> CtResult<()> {
                     _ => "❌ Just kidding, I know you love me 😄",
                 };
                 draw_frame(
-                    stdout,
+                    backend,
                     "FINAL LOCK",
-                    &vec![msg.into()],
+                    &[plain(msg)],
                     "Press any key to retry",
-                    4,
-                    4,
+                    lock_total,
+                    lock_total,
                     "RETRY",
                     started,
                 )?;
-                wait_any_key()?;
+                wait_any_key(backend)?;
             }
             _ => {}
         }
@@ -341,65 +462,368 @@ fn final_lock(stdout: &mut impl Write, started: Instant) -> CtResult<()> {
     Ok(())
 }
 
-fn main() -> CtResult<()> {
-    let _guard = TermGuard::init()?;
-    let mut stdout = io::stdout();
-    let started = Instant::now();
-
-    let locks = vec![
-        McQuestion {
-            title: "LOCK 1: FIRST DATE",
-            prompt: "Where was our first date?",
-            a: "Kiitsu",
-            b: "Raising Canes",
-            c: "Six Flags",
-            d: "San Diego",
-            correct: 'A',
-            wrong_msg: "Hint: You like sushi don't you? 🍣",
-        },
-        McQuestion {
-            title: "LOCK 2: FIRST HUG",
-            prompt: "When did we first hug?",
-            a: "Joshua Tree",
-            b: "The beach",
-            c: "Dining In",
-            d: "All of the above",
-            correct: 'A',
-            wrong_msg: "Hint: flightline chaos 😄",
-        },
-        McQuestion {
-            title: "LOCK 3: I LOVE YOU SO MUCH THAT I'll...",
-            prompt: "What game did we play when we were getting to know each other?",
-            a: "It Takes Two",
-            b: "Overcooked",
-            c: "Fortnite",
-            d: "Animal Crossing",
-            correct: 'C',
-            wrong_msg: "Hint: I carried so hard, its a battle royal game! 🎮",
-        },
-    ];
-
-    draw_frame(
-        &mut stdout,
-        "OPERATION: VALENTINE",
-        &vec!["Press any key to begin".into()],
-        "Controls: A/B/C/D, Y/N",
-        0,
-        4,
-        "STANDBY",
-        started,
+/// Render the review table of every lock's State / Title / Result, highlighting
+/// the selected row and scrolling (with padding) so it stays visible when the
+/// list is taller than the viewport.
+fn draw_review(
+    backend: &mut impl Backend,
+    questions: &[OwnedQuestion],
+    progress: &Progress,
+    selected: usize,
+    lock_total: usize,
+    started: Instant,
+) -> CtResult<()> {
+    let (w, h) = backend.size();
+    backend.clear()?;
+
+    let title = "REVIEW";
+    backend.move_to(center_x(w, title), 1)?;
+    backend.print_styled(title.to_string().bold())?;
+
+    let first_row = 4u16;
+    let last_row = h.saturating_sub(3);
+    let visible = last_row.saturating_sub(first_row).max(1) as usize;
+
+    // Centre the selection in the viewport, clamped to the list bounds.
+    let max_scroll = questions.len().saturating_sub(visible);
+    let scroll = selected.saturating_sub(visible / 2).min(max_scroll);
+
+    let result_w = 12usize;
+    let glyph_w = 2usize;
+    let title_w = (w as usize)
+        .saturating_sub(glyph_w + result_w + 6)
+        .max(4);
+
+    backend.move_to(2, first_row.saturating_sub(1))?;
+    backend.print_styled(
+        format!(
+            "{gl:<glyph_w$}  {ti:<title_w$}  {re:>result_w$}",
+            gl = "St",
+            ti = "Title",
+            re = "Result",
+        )
+        .dim(),
     )?;
-    wait_any_key()?;
 
-    jet_cutscene(&mut stdout, started)?;
+    for (row, i) in (scroll..questions.len().min(scroll + visible)).enumerate() {
+        let state = progress.states[i];
+        let title: String = questions[i].title.chars().take(title_w).collect();
+        let line = format!(
+            "{gl:<glyph_w$}  {ti:<title_w$}  {re:>result_w$}",
+            gl = state.glyph(),
+            ti = title,
+            re = state.result(),
+        );
+        backend.move_to(2, first_row + row as u16)?;
+        if i == selected {
+            backend.print_styled(line.reverse())?;
+        } else {
+            backend.print_styled(line.stylize())?;
+        }
+    }
+
+    backend.move_to(1, h.saturating_sub(3))?;
+    backend.print_styled(
+        "↑/↓ move · Enter revisit · Esc back"
+            .to_string()
+            .dim(),
+    )?;
+    draw_status_bar(backend, w, h, selected + 1, lock_total, "REVIEW", started)?;
+    backend.flush()?;
+    Ok(())
+}
+
+/// Navigable overview of every lock. Up/Down move the highlight, Enter revisits
+/// the selected question, Esc leaves the screen.
+fn review_loop(
+    backend: &mut impl Backend,
+    questions: &[OwnedQuestion],
+    progress: &mut Progress,
+    lock_total: usize,
+    started: Instant,
+) -> CtResult<()> {
+    if questions.is_empty() {
+        return Ok(());
+    }
+    let mut selected = 0usize;
+    loop {
+        draw_review(backend, questions, progress, selected, lock_total, started)?;
+        if let Event::Key(k) = backend.read_event()? {
+            // Ctrl-Q still aborts the whole run; Esc just leaves the review.
+            if k.modifiers.contains(KeyModifiers::CONTROL)
+                && matches!(k.code, KeyCode::Char('q') | KeyCode::Char('Q'))
+            {
+                return Err(aborted());
+            }
+            match k.code {
+                KeyCode::Esc => return Ok(()),
+                KeyCode::Up => selected = selected.saturating_sub(1),
+                KeyCode::Down if selected + 1 < questions.len() => selected += 1,
+                KeyCode::Enter => {
+                    ask_mc(
+                        backend,
+                        &questions[selected],
+                        selected + 1,
+                        lock_total,
+                        started,
+                        progress,
+                    )?;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Run the full quiz against `backend`. Returns an `Interrupted` error if the
+/// player aborts with Esc / Ctrl-Q.
+fn play(backend: &mut impl Backend, started: Instant) -> CtResult<()> {
+    let locks = load_questions();
+    // The MC locks plus the final Y/N lock make up the total lock count.
+    let lock_total = locks.len() + 1;
+
+    let mut progress = Progress::new(locks.len());
+
+    loop {
+        draw_frame(
+            backend,
+            "OPERATION: VALENTINE",
+            &[plain("Press any key to begin")],
+            "Controls: A/B/C/D, Y/N · R to review · Esc/Ctrl-Q to quit",
+            0,
+            lock_total,
+            "STANDBY",
+            started,
+        )?;
+        if let Event::Key(k) = backend.read_event()? {
+            if is_abort_key(&k) {
+                return Err(aborted());
+            }
+            // Peek at progress before starting; any other key begins the quiz.
+            if matches!(k.code, KeyCode::Char('r') | KeyCode::Char('R')) {
+                review_loop(backend, &locks, &mut progress, lock_total, started)?;
+                continue;
+            }
+            break;
+        }
+    }
+
+    jet_cutscene(backend, lock_total, started)?;
 
     for (i, q) in locks.iter().enumerate() {
-        ask_mc(&mut stdout, q, i + 1, 4, started)?;
+        ask_mc(backend, q, i + 1, lock_total, started, &mut progress)?;
     }
 
-    final_lock(&mut stdout, started)?;
+    // Offer a review/revisit pass before the final lock.
+    review_loop(backend, &locks, &mut progress, lock_total, started)?;
+
+    final_lock(backend, lock_total, started)?;
     Ok(())
 }
 
+fn main() -> CtResult<()> {
+    backend::install_panic_hook();
+    backend::install_signal_handler();
+
+    let mut guard = TermGuard::init(CrosstermBackend::new())?;
+    let result = play(guard.backend(), Instant::now());
+    drop(guard);
+
+    match result {
+        // A clean user-requested abort is a normal exit, not a failure.
+        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => Ok(()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use backend::TestBackend;
+    use crossterm::event::KeyCode;
+
+    fn sample_question() -> OwnedQuestion {
+        OwnedQuestion {
+            title: "LOCK 1: FIRST DATE".into(),
+            prompt: "Where was our first date?".into(),
+            a: "Kiitsu".into(),
+            b: "Raising Canes".into(),
+            c: "Six Flags".into(),
+            d: "San Diego".into(),
+            correct: 'A',
+            wrong_msg: "Hint: You like sushi don't you? 🍣".into(),
+        }
+    }
+
+    #[test]
+    fn wrap_line_breaks_on_word_boundaries() {
+        let wrapped = wrap_line("the quick brown fox", 10);
+        assert_eq!(wrapped, vec!["the quick", "brown fox"]);
+        assert!(wrapped.iter().all(|l| l.chars().count() <= 10));
+    }
+
+    #[test]
+    fn wrap_line_hard_breaks_long_tokens() {
+        let wrapped = wrap_line("supercalifragilistic", 5);
+        assert_eq!(wrapped, vec!["super", "calif", "ragil", "istic"]);
+    }
+
+    #[test]
+    fn wrap_line_preserves_blank_separators() {
+        assert_eq!(wrap_line("", 10), vec![String::new()]);
+    }
+
+    #[test]
+    fn wrap_lines_wraps_on_visible_text_not_escapes() {
+        // A short styled line must not wrap just because its ANSI escapes push
+        // the raw byte length past the width.
+        let styled = "✅ hi".to_string().green();
+        let rows = wrap_lines(&[styled], 6);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].content(), "✅ hi");
+        // Style is preserved on the emitted row.
+        assert_eq!(
+            rows[0].style().foreground_color,
+            Some(crossterm::style::Color::Green)
+        );
+    }
+
+    #[test]
+    fn status_bar_drops_segments_on_tiny_width() {
+        let mut backend = TestBackend::new(12, 6);
+        draw_status_bar(&mut backend, 12, 6, 1, 4, "AWAITING INPUT", Instant::now()).unwrap();
+        let bottom = backend.buffer_to_string();
+        let bottom = bottom.lines().last().unwrap_or_default();
+        // LOCK always survives; the overlapping STATUS segment is dropped.
+        assert!(bottom.contains("LOCK 1/4"));
+        assert!(!bottom.contains("STATUS"));
+    }
+
+    #[test]
+    fn ask_mc_accepts_correct_answer() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_chars("A"); // correct answer
+        backend.script_chars(" "); // dismiss the "Correct!" screen
+
+        let mut progress = Progress::new(3);
+        ask_mc(&mut backend, &sample_question(), 1, 3, Instant::now(), &mut progress).unwrap();
+
+        backend.assert_contains("✅ Correct!");
+        backend.assert_contains("LOCK 1/3");
+        backend.assert_contains("STATUS: PASS");
+        assert_eq!(progress.states[0], LockState::Passed);
+    }
+
+    #[test]
+    fn ask_mc_retries_until_correct() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_chars("B"); // wrong answer
+        backend.script_chars(" "); // dismiss the "Incorrect" screen
+        backend.script_chars("A"); // correct answer
+        backend.script_chars(" "); // dismiss the "Correct!" screen
+
+        let mut progress = Progress::new(3);
+        ask_mc(&mut backend, &sample_question(), 2, 3, Instant::now(), &mut progress).unwrap();
+
+        // Only the final "Correct!" frame survives on the grid.
+        backend.assert_contains("✅ Correct!");
+        backend.assert_contains("LOCK 2/3");
+        assert_eq!(progress.states[1], LockState::Passed);
+    }
+
+    #[test]
+    fn review_screen_lists_locks_and_states() {
+        let mut backend = TestBackend::new(80, 24);
+        let questions = vec![sample_question()];
+        let mut progress = Progress::new(1);
+        progress.record_wrong(0);
+        progress.record_wrong(0);
+        backend.script_key(KeyCode::Esc); // leave immediately
+
+        review_loop(&mut backend, &questions, &mut progress, 2, Instant::now()).unwrap();
+
+        backend.assert_contains("REVIEW");
+        backend.assert_contains("LOCK 1: FIRST DATE");
+        backend.assert_contains("retried 2x");
+    }
+
+    #[test]
+    fn review_screen_enter_revisits_question() {
+        let mut backend = TestBackend::new(80, 24);
+        let questions = vec![sample_question()];
+        let mut progress = Progress::new(1);
+        backend.script_key(KeyCode::Enter); // revisit row 0
+        backend.script_chars("A"); // answer it correctly
+        backend.script_chars(" "); // dismiss the "Correct!" screen
+        backend.script_key(KeyCode::Esc); // leave the review
+
+        review_loop(&mut backend, &questions, &mut progress, 2, Instant::now()).unwrap();
+
+        assert_eq!(progress.states[0], LockState::Passed);
+    }
+
+    #[test]
+    fn final_lock_accepts_yes() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_chars("Y"); // say yes
+        backend.script_chars(" "); // dismiss the success screen
+
+        final_lock(&mut backend, 4, Instant::now()).unwrap();
+
+        backend.assert_contains("MISSION SUCCESS");
+        backend.assert_contains("VALENTINE AUTHORIZED");
+        backend.assert_contains("STATUS: SUCCESS");
+    }
+
+    #[test]
+    fn test_backend_records_cell_styles() {
+        use crossterm::style::Color;
+        let mut backend = TestBackend::new(80, 24);
+        backend.move_to(3, 1).unwrap();
+        backend.print_styled("hi".to_string().green()).unwrap();
+
+        assert_eq!(backend.style_at(3, 1).foreground_color, Some(Color::Green));
+        // An untouched cell keeps the default (unstyled) slot.
+        assert_eq!(backend.style_at(0, 0).foreground_color, None);
+    }
+
+    #[test]
+    fn status_bar_positions_lock_counter_at_left_edge() {
+        let mut backend = TestBackend::new(80, 24);
+        draw_status_bar(&mut backend, 80, 24, 1, 4, "STANDBY", Instant::now()).unwrap();
+
+        // LOCK counter starts one column in on the bottom row.
+        let row = backend.buffer_to_string();
+        let bottom = row.lines().last().unwrap_or_default();
+        assert!(bottom.starts_with(" LOCK 1/4"));
+    }
+
+    #[test]
+    fn read_abcd_aborts_on_esc() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_key(KeyCode::Esc);
 
+        let err = read_abcd(&mut backend).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Interrupted);
+    }
 
+    #[test]
+    fn read_yn_aborts_on_ctrl_q() {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+        let err = read_yn(&mut backend).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Interrupted);
+    }
+
+    #[test]
+    fn key_reader_ignores_unrelated_keys() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.script_key(KeyCode::Enter); // ignored
+        backend.script_chars("x"); // ignored
+        backend.script_chars("C"); // accepted
+
+        assert_eq!(read_abcd(&mut backend).unwrap(), 'C');
+    }
+}
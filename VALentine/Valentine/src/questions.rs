@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// Environment variable pointing at a quiz file, used when no CLI path is given.
+const QUESTIONS_ENV: &str = "VALENTINE_QUESTIONS";
+
+/// An owned multiple-choice question.
+///
+/// Unlike the original `&'static str` form, every field is a `String`, so quizzes
+/// can be deserialized from an external file at runtime instead of being baked in
+/// at compile time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OwnedQuestion {
+    pub title: String,
+    pub prompt: String,
+    pub a: String,
+    pub b: String,
+    pub c: String,
+    pub d: String,
+    /// Correct option letter. Normalized to uppercase on load so a file may use
+    /// either case (`correct = "a"` and `correct = "A"` both work).
+    pub correct: char,
+    pub wrong_msg: String,
+}
+
+/// Deserialization wrapper matching a `[[questions]]` TOML array or a
+/// `{ "questions": [...] }` JSON object.
+#[derive(Debug, Deserialize)]
+struct QuestionFile {
+    questions: Vec<OwnedQuestion>,
+}
+
+/// Resolve the quiz to run: the first CLI argument or the [`QUESTIONS_ENV`]
+/// variable naming a TOML/JSON file, falling back to the built-in set when no
+/// path is given or the file can't be loaded.
+pub fn load_questions() -> Vec<OwnedQuestion> {
+    let path = std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var(QUESTIONS_ENV).ok());
+
+    match path {
+        Some(path) => load_from_file(&path).unwrap_or_else(|err| {
+            eprintln!("failed to load questions from {path}: {err}; using built-in set");
+            default_questions()
+        }),
+        None => default_questions(),
+    }
+}
+
+fn load_from_file(path: &str) -> Result<Vec<OwnedQuestion>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut file: QuestionFile = match Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("json") => serde_json::from_str(&text).map_err(|e| e.to_string())?,
+        Some("toml") => toml::from_str(&text).map_err(|e| e.to_string())?,
+        other => {
+            return Err(format!(
+                "unsupported question file extension: {}",
+                other.unwrap_or("<none>")
+            ))
+        }
+    };
+    if file.questions.is_empty() {
+        return Err("question file contains no questions".to_string());
+    }
+    for q in &mut file.questions {
+        q.correct = q.correct.to_ascii_uppercase();
+    }
+    Ok(file.questions)
+}
+
+/// The quiz that ships with the binary, used when no external file is supplied.
+pub fn default_questions() -> Vec<OwnedQuestion> {
+    vec![
+        OwnedQuestion {
+            title: "LOCK 1: FIRST DATE".into(),
+            prompt: "Where was our first date?".into(),
+            a: "Kiitsu".into(),
+            b: "Raising Canes".into(),
+            c: "Six Flags".into(),
+            d: "San Diego".into(),
+            correct: 'A',
+            wrong_msg: "Hint: You like sushi don't you? 🍣".into(),
+        },
+        OwnedQuestion {
+            title: "LOCK 2: FIRST HUG".into(),
+            prompt: "When did we first hug?".into(),
+            a: "Joshua Tree".into(),
+            b: "The beach".into(),
+            c: "Dining In".into(),
+            d: "All of the above".into(),
+            correct: 'A',
+            wrong_msg: "Hint: flightline chaos 😄".into(),
+        },
+        OwnedQuestion {
+            title: "LOCK 3: I LOVE YOU SO MUCH THAT I'll...".into(),
+            prompt: "What game did we play when we were getting to know each other?".into(),
+            a: "It Takes Two".into(),
+            b: "Overcooked".into(),
+            c: "Fortnite".into(),
+            d: "Animal Crossing".into(),
+            correct: 'C',
+            wrong_msg: "Hint: I carried so hard, its a battle royal game! 🎮".into(),
+        },
+    ]
+}